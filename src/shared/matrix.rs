@@ -1,4 +1,8 @@
 use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
 
 /// Fixed-size Matrix with const-generics
 #[derive(Clone, Copy, PartialEq)]
@@ -17,6 +21,443 @@ impl<T: Default + Copy, const R: usize, const C: usize> Matrix<T, R, C> {
     pub const fn get(&self, i: usize, j: usize) -> &T {
         &self.data[i][j]
     }
+
+    #[inline]
+    pub fn get_mut(&mut self, i: usize, j: usize) -> &mut T {
+        &mut self.data[i][j]
+    }
+
+    #[inline]
+    pub fn set(&mut self, i: usize, j: usize, v: T) {
+        self.data[i][j] = v;
+    }
+
+    /// Unchecked element access. Skips the bounds check `get` performs.
+    ///
+    /// # Safety
+    ///
+    /// `i < R` and `j < C` must hold; otherwise this is undefined behavior.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, i: usize, j: usize) -> &T {
+        unsafe { self.data.get_unchecked(i).get_unchecked(j) }
+    }
+
+    /// Unchecked mutable element access. Skips the bounds check `get_mut` performs.
+    ///
+    /// # Safety
+    ///
+    /// `i < R` and `j < C` must hold; otherwise this is undefined behavior.
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, i: usize, j: usize) -> &mut T {
+        unsafe { self.data.get_unchecked_mut(i).get_unchecked_mut(j) }
+    }
+
+    /// Builds a matrix by calling `f(i, j)` for every cell.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Self {
+        let mut out = Self::new();
+        for i in 0..R {
+            for j in 0..C {
+                out.data[i][j] = f(i, j);
+            }
+        }
+        out
+    }
+
+    /// Builds a matrix directly from a row-major array of rows.
+    pub fn from_rows(rows: [[T; C]; R]) -> Self {
+        Self { data: rows }
+    }
+
+    /// Fills a matrix by drawing each element from `rng`'s standard
+    /// distribution for `T`.
+    pub fn from_rng<Rg: Rng + ?Sized>(rng: &mut Rg) -> Self
+    where
+        StandardUniform: Distribution<T>,
+    {
+        Self::from_fn(|_, _| rng.random())
+    }
+
+    /// Fills a matrix by drawing each element from the given distribution,
+    /// e.g. a uniform range or normal distribution from `rand_distr`.
+    pub fn sample_from<D: Distribution<T>, Rg: Rng + ?Sized>(dist: &D, rng: &mut Rg) -> Self {
+        Self::from_fn(|_, _| dist.sample(rng))
+    }
+
+    /// Iterates over rows, each yielded as a fixed-size row slice.
+    pub fn rows(&self) -> impl Iterator<Item = &[T; C]> {
+        self.data.iter()
+    }
+
+    /// Iterates over columns; each column is itself an iterator over its
+    /// elements top-to-bottom.
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..C).map(move |j| (0..R).map(move |i| &self.data[i][j]))
+    }
+
+    /// Iterates over every element in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter().flat_map(|row| row.iter())
+    }
+}
+
+impl<T: Default + Copy, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data[i][j]
+    }
+}
+
+impl<T: Default + Copy, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data[i][j]
+    }
+}
+
+impl<T, const R: usize, const C: usize> Distribution<Matrix<T, R, C>> for StandardUniform
+where
+    T: Default + Copy,
+    StandardUniform: Distribution<T>,
+{
+    fn sample<Rg: Rng + ?Sized>(&self, rng: &mut Rg) -> Matrix<T, R, C> {
+        Matrix::from_rng(rng)
+    }
+}
+
+impl<T: Default + Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Transposes the matrix, swapping rows and columns.
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        let mut out = Matrix::<T, C, R>::new();
+        for i in 0..R {
+            for j in 0..C {
+                out.data[j][i] = self.data[i][j];
+            }
+        }
+        out
+    }
+}
+
+impl<T, const R: usize, const K: usize> Matrix<T, R, K>
+where
+    T: Add<Output = T> + Mul<Output = T> + Default + Copy,
+{
+    /// Multiplies this `R x K` matrix by a `K x C` matrix, yielding an `R x C`
+    /// result. The shared inner dimension `K` is enforced at compile time, so
+    /// mismatched dimensions fail to compile rather than panic at runtime.
+    pub fn matmul<const C: usize>(&self, rhs: &Matrix<T, K, C>) -> Matrix<T, R, C> {
+        let mut out = Matrix::<T, R, C>::new();
+        for i in 0..R {
+            for j in 0..C {
+                let mut sum = T::default();
+                for k in 0..K {
+                    sum = sum + self.data[i][k] * rhs.data[k][j];
+                }
+                out.data[i][j] = sum;
+            }
+        }
+        out
+    }
+}
+
+impl<T, const R: usize, const K: usize, const C: usize> Mul<Matrix<T, K, C>> for Matrix<T, R, K>
+where
+    T: Add<Output = T> + Mul<Output = T> + Default + Copy,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn mul(self, rhs: Matrix<T, K, C>) -> Self::Output {
+        self.matmul(&rhs)
+    }
+}
+
+impl<T: Add<Output = T> + Default + Copy, const R: usize, const C: usize> Add for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(&rhs, |a, b| a + b)
+    }
+}
+
+impl<T: Sub<Output = T> + Default + Copy, const R: usize, const C: usize> Sub for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(&rhs, |a, b| a - b)
+    }
+}
+
+impl<T: Mul<Output = T> + Default + Copy, const R: usize, const C: usize> Mul<T> for Matrix<T, R, C> {
+    type Output = Self;
+
+    /// Scales every element by `scalar`.
+    fn mul(self, scalar: T) -> Self::Output {
+        self.map_scalar(scalar, |a, b| a * b)
+    }
+}
+
+/// Per-element integer arithmetic with an explicit overflow policy, mirroring
+/// the `Wrapping<T>` semantics used elsewhere for hash/RNG arithmetic so that
+/// `wrapping_*` results are identical across build profiles.
+pub trait OverflowArith: Sized {
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_overflow_arith {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl OverflowArith for $t {
+                fn wrapping_add(self, rhs: Self) -> Self { <$t>::wrapping_add(self, rhs) }
+                fn wrapping_sub(self, rhs: Self) -> Self { <$t>::wrapping_sub(self, rhs) }
+                fn wrapping_mul(self, rhs: Self) -> Self { <$t>::wrapping_mul(self, rhs) }
+                fn checked_add(self, rhs: Self) -> Option<Self> { <$t>::checked_add(self, rhs) }
+                fn checked_sub(self, rhs: Self) -> Option<Self> { <$t>::checked_sub(self, rhs) }
+                fn checked_mul(self, rhs: Self) -> Option<Self> { <$t>::checked_mul(self, rhs) }
+                fn saturating_add(self, rhs: Self) -> Self { <$t>::saturating_add(self, rhs) }
+                fn saturating_sub(self, rhs: Self) -> Self { <$t>::saturating_sub(self, rhs) }
+                fn saturating_mul(self, rhs: Self) -> Self { <$t>::saturating_mul(self, rhs) }
+            }
+        )*
+    };
+}
+
+impl_overflow_arith!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Selects how element-wise integer arithmetic on a [`Matrix`] handles
+/// overflow, instead of relying on debug-mode panics / release-mode wrapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Wrap around on overflow, matching `Wrapping<T>` semantics.
+    Wrapping,
+    /// Return `None` if any element overflows.
+    Checked,
+    /// Clamp to the type's min/max on overflow.
+    Saturating,
+}
+
+impl<T: Default + Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    fn zip_with<F: Fn(T, T) -> T>(&self, rhs: &Self, f: F) -> Self {
+        let mut out = Self::new();
+        for i in 0..R {
+            for j in 0..C {
+                out.data[i][j] = f(self.data[i][j], rhs.data[i][j]);
+            }
+        }
+        out
+    }
+
+    fn try_zip_with<F: Fn(T, T) -> Option<T>>(&self, rhs: &Self, f: F) -> Option<Self> {
+        let mut out = Self::new();
+        for i in 0..R {
+            for j in 0..C {
+                out.data[i][j] = f(self.data[i][j], rhs.data[i][j])?;
+            }
+        }
+        Some(out)
+    }
+
+    fn map_scalar<F: Fn(T, T) -> T>(&self, scalar: T, f: F) -> Self {
+        let mut out = Self::new();
+        for i in 0..R {
+            for j in 0..C {
+                out.data[i][j] = f(self.data[i][j], scalar);
+            }
+        }
+        out
+    }
+
+    fn try_map_scalar<F: Fn(T, T) -> Option<T>>(&self, scalar: T, f: F) -> Option<Self> {
+        let mut out = Self::new();
+        for i in 0..R {
+            for j in 0..C {
+                out.data[i][j] = f(self.data[i][j], scalar)?;
+            }
+        }
+        Some(out)
+    }
+}
+
+impl<T: OverflowArith + Default + Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, T::wrapping_add)
+    }
+
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, T::wrapping_sub)
+    }
+
+    pub fn wrapping_mul(&self, scalar: T) -> Self {
+        self.map_scalar(scalar, T::wrapping_mul)
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        self.try_zip_with(rhs, T::checked_add)
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.try_zip_with(rhs, T::checked_sub)
+    }
+
+    pub fn checked_mul(&self, scalar: T) -> Option<Self> {
+        self.try_map_scalar(scalar, T::checked_mul)
+    }
+
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, T::saturating_add)
+    }
+
+    pub fn saturating_sub(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, T::saturating_sub)
+    }
+
+    pub fn saturating_mul(&self, scalar: T) -> Self {
+        self.map_scalar(scalar, T::saturating_mul)
+    }
+
+    /// Adds `rhs` element-wise under the given [`Overflow`] policy. `Checked`
+    /// yields `None` if any element overflows; the other modes always
+    /// succeed.
+    pub fn add_with(&self, rhs: &Self, mode: Overflow) -> Option<Self> {
+        match mode {
+            Overflow::Wrapping => Some(self.wrapping_add(rhs)),
+            Overflow::Checked => self.checked_add(rhs),
+            Overflow::Saturating => Some(self.saturating_add(rhs)),
+        }
+    }
+
+    /// Subtracts `rhs` element-wise under the given [`Overflow`] policy.
+    pub fn sub_with(&self, rhs: &Self, mode: Overflow) -> Option<Self> {
+        match mode {
+            Overflow::Wrapping => Some(self.wrapping_sub(rhs)),
+            Overflow::Checked => self.checked_sub(rhs),
+            Overflow::Saturating => Some(self.saturating_sub(rhs)),
+        }
+    }
+
+    /// Scales every element by `scalar` under the given [`Overflow`] policy.
+    pub fn mul_with(&self, scalar: T, mode: Overflow) -> Option<Self> {
+        match mode {
+            Overflow::Wrapping => Some(self.wrapping_mul(scalar)),
+            Overflow::Checked => self.checked_mul(scalar),
+            Overflow::Saturating => Some(self.saturating_mul(scalar)),
+        }
+    }
+}
+
+/// Types with a maximum sentinel value, used by the graph algorithms below to
+/// mean "no edge" / "unreachable" without reserving a separate `Option` slot.
+pub trait Bounded: Sized {
+    const MAX: Self;
+}
+
+macro_rules! impl_bounded {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Bounded for $t {
+                const MAX: Self = <$t>::MAX;
+            }
+        )*
+    };
+}
+
+impl_bounded!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+/// Graph algorithms over a square matrix interpreted as an edge-weight
+/// adjacency matrix: `self[(i, j)]` is the weight of the edge from `i` to
+/// `j`, and [`Bounded::MAX`] marks the absence of an edge.
+impl<W, const N: usize> Matrix<W, N, N>
+where
+    W: Bounded + PartialOrd + Add<Output = W> + Sub<Output = W> + Default + Copy,
+{
+    /// Computes all-pairs shortest paths via Floyd-Warshall:
+    /// `dist[i][j] = min(dist[i][j], dist[i][k] + dist[k][j])` for every
+    /// intermediate `k`.
+    ///
+    /// The diagonal is initialized to zero (distance from a node to itself)
+    /// before relaxation. `W::MAX` means "no edge" / "unreachable"; paths
+    /// through an unreachable intermediate are skipped. A leg is also
+    /// skipped whenever summing it would exceed `W::MAX` — not just when one
+    /// side already equals the sentinel — so two large, genuinely finite
+    /// weights can never overflow the accumulator. Runs in O(N^3).
+    pub fn floyd_warshall(&self) -> Matrix<W, N, N> {
+        let mut dist = *self;
+        for i in 0..N {
+            dist.data[i][i] = W::default();
+        }
+
+        for k in 0..N {
+            for i in 0..N {
+                if dist.data[i][k] == W::MAX {
+                    continue;
+                }
+                for j in 0..N {
+                    if dist.data[k][j] == W::MAX {
+                        continue;
+                    }
+                    if dist.data[k][j] > W::default()
+                        && dist.data[i][k] > W::MAX - dist.data[k][j]
+                    {
+                        continue;
+                    }
+                    let through = dist.data[i][k] + dist.data[k][j];
+                    if through < dist.data[i][j] {
+                        dist.data[i][j] = through;
+                    }
+                }
+            }
+        }
+
+        dist
+    }
+
+    /// Solves the minimax (bottleneck) path problem: entry `(i, j)` of the
+    /// result is the smallest possible value of the largest edge on any path
+    /// from `i` to `j` — e.g. the heaviest link a path is forced to cross.
+    ///
+    /// Same sentinel/unreachable convention and diagonal initialization as
+    /// [`Matrix::floyd_warshall`], but relaxes with
+    /// `dist[i][j] = min(dist[i][j], max(dist[i][k], dist[k][j]))` instead of
+    /// summing. Runs in O(N^3).
+    pub fn min_bottleneck(&self) -> Matrix<W, N, N> {
+        let mut dist = *self;
+        for i in 0..N {
+            dist.data[i][i] = W::default();
+        }
+
+        for k in 0..N {
+            for i in 0..N {
+                if dist.data[i][k] == W::MAX {
+                    continue;
+                }
+                for j in 0..N {
+                    if dist.data[k][j] == W::MAX {
+                        continue;
+                    }
+                    let bottleneck = if dist.data[i][k] > dist.data[k][j] {
+                        dist.data[i][k]
+                    } else {
+                        dist.data[k][j]
+                    };
+                    if bottleneck < dist.data[i][j] {
+                        dist.data[i][j] = bottleneck;
+                    }
+                }
+            }
+        }
+
+        dist
+    }
 }
 
 impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<T, R, C> {
@@ -124,7 +565,7 @@ impl<T: fmt::Display, const R: usize, const C: usize> fmt::Debug for Matrix<T, R
 
 #[cfg(test)]
 mod tests {
-    use super::Matrix;
+    use super::{Matrix, Overflow};
     use rand::distr::{Distribution, StandardUniform};
     use rand::{Rng, SeedableRng, rngs::StdRng};
 
@@ -133,18 +574,7 @@ mod tests {
         T: Default + Copy,
         StandardUniform: Distribution<T>,
     {
-        #[allow(unused_mut)]
-        let mut m = Matrix::<T, R, C>::new();
-        for i in 0..R {
-            for j in 0..C {
-                let val: T = rng.random();
-                unsafe {
-                    let ptr = (&m as *const _ as *mut Matrix<T, R, C>).as_mut().unwrap();
-                    (*ptr).data[i][j] = val;
-                }
-            }
-        }
-        m
+        Matrix::from_rng(rng)
     }
 
     #[test]
@@ -227,4 +657,254 @@ mod tests {
         let m = random_matrix::<u8, 3, 4>(&mut rng);
         println!("{m:?}");
     }
+
+    #[test]
+    fn matmul_identity() {
+        let a = Matrix::<i32, 2, 3> {
+            data: [[1, 2, 3], [4, 5, 6]],
+        };
+        let identity = Matrix::<i32, 3, 3> {
+            data: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+        };
+        let result = a * identity;
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(*result.get(i, j), *a.get(i, j));
+            }
+        }
+    }
+
+    #[test]
+    fn matmul_known_values() {
+        let a = Matrix::<i32, 2, 2> {
+            data: [[1, 2], [3, 4]],
+        };
+        let b = Matrix::<i32, 2, 2> {
+            data: [[5, 6], [7, 8]],
+        };
+        let result = a.matmul(&b);
+        assert_eq!(*result.get(0, 0), 19);
+        assert_eq!(*result.get(0, 1), 22);
+        assert_eq!(*result.get(1, 0), 43);
+        assert_eq!(*result.get(1, 1), 50);
+    }
+
+    #[test]
+    fn wrapping_add_matches_wrapping_semantics() {
+        let a = Matrix::<u8, 1, 2> { data: [[250, 10]] };
+        let b = Matrix::<u8, 1, 2> { data: [[10, 10]] };
+        let result = a.wrapping_add(&b);
+        assert_eq!(*result.get(0, 0), 250u8.wrapping_add(10));
+        assert_eq!(*result.get(0, 1), 20);
+    }
+
+    #[test]
+    fn checked_add_none_on_overflow() {
+        let a = Matrix::<u8, 1, 1> { data: [[250]] };
+        let b = Matrix::<u8, 1, 1> { data: [[10]] };
+        assert_eq!(a.checked_add(&b), None);
+
+        let c = Matrix::<u8, 1, 1> { data: [[1]] };
+        assert!(a.checked_add(&c).is_some());
+    }
+
+    #[test]
+    fn saturating_sub_clamps_at_min() {
+        let a = Matrix::<u8, 1, 1> { data: [[5]] };
+        let b = Matrix::<u8, 1, 1> { data: [[10]] };
+        let result = a.saturating_sub(&b);
+        assert_eq!(*result.get(0, 0), 0);
+    }
+
+    #[test]
+    fn overflow_enum_dispatch() {
+        let a = Matrix::<u8, 1, 1> { data: [[250]] };
+        let b = Matrix::<u8, 1, 1> { data: [[10]] };
+
+        assert_eq!(a.add_with(&b, Overflow::Checked), None);
+        assert_eq!(
+            *a.add_with(&b, Overflow::Wrapping).unwrap().get(0, 0),
+            250u8.wrapping_add(10)
+        );
+        assert_eq!(*a.add_with(&b, Overflow::Saturating).unwrap().get(0, 0), 255);
+    }
+
+    #[test]
+    fn plain_elementwise_ops() {
+        let a = Matrix::<i32, 1, 2> { data: [[1, 2]] };
+        let b = Matrix::<i32, 1, 2> { data: [[10, 20]] };
+        let sum = a + b;
+        assert_eq!(*sum.get(0, 0), 11);
+        assert_eq!(*sum.get(0, 1), 22);
+
+        let diff = b - a;
+        assert_eq!(*diff.get(0, 0), 9);
+        assert_eq!(*diff.get(0, 1), 18);
+
+        let scaled = a * 3;
+        assert_eq!(*scaled.get(0, 0), 3);
+        assert_eq!(*scaled.get(0, 1), 6);
+    }
+
+    #[test]
+    fn transpose_swaps_dimensions() {
+        let a = Matrix::<i32, 2, 3> {
+            data: [[1, 2, 3], [4, 5, 6]],
+        };
+        let t = a.transpose();
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(*a.get(i, j), *t.get(j, i));
+            }
+        }
+    }
+
+    #[test]
+    fn set_and_get_mut_roundtrip() {
+        let mut m = Matrix::<i32, 2, 2>::new();
+        m.set(0, 1, 7);
+        *m.get_mut(1, 0) = 9;
+        assert_eq!(*m.get(0, 1), 7);
+        assert_eq!(*m.get(1, 0), 9);
+    }
+
+    #[test]
+    fn index_and_index_mut() {
+        let mut m = Matrix::<i32, 2, 2>::new();
+        m[(0, 0)] = 3;
+        m[(1, 1)] = 4;
+        assert_eq!(m[(0, 0)], 3);
+        assert_eq!(m[(1, 1)], 4);
+    }
+
+    #[test]
+    fn get_unchecked_matches_get() {
+        let m = Matrix::<i32, 2, 2>::from_rows([[1, 2], [3, 4]]);
+        unsafe {
+            assert_eq!(*m.get_unchecked(1, 0), *m.get(1, 0));
+        }
+    }
+
+    #[test]
+    fn from_fn_builds_expected_matrix() {
+        let m = Matrix::<i32, 2, 3>::from_fn(|i, j| (i * 3 + j) as i32);
+        assert_eq!(*m.get(0, 0), 0);
+        assert_eq!(*m.get(1, 2), 5);
+    }
+
+    #[test]
+    fn from_rows_matches_literal() {
+        let m = Matrix::<i32, 2, 2>::from_rows([[1, 2], [3, 4]]);
+        assert_eq!(*m.get(0, 1), 2);
+        assert_eq!(*m.get(1, 0), 3);
+    }
+
+    #[test]
+    fn rows_cols_and_iter() {
+        let m = Matrix::<i32, 2, 2>::from_rows([[1, 2], [3, 4]]);
+
+        let rows: Vec<_> = m.rows().copied().collect();
+        assert_eq!(rows, vec![[1, 2], [3, 4]]);
+
+        let cols: Vec<Vec<i32>> = m.cols().map(|c| c.copied().collect()).collect();
+        assert_eq!(cols, vec![vec![1, 3], vec![2, 4]]);
+
+        let flat: Vec<i32> = m.iter().copied().collect();
+        assert_eq!(flat, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn from_rng_fills_every_cell() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let m = Matrix::<u8, 3, 3>::from_rng(&mut rng);
+        let mut rng2 = StdRng::seed_from_u64(7);
+        let expected = Matrix::<u8, 3, 3>::from_fn(|_, _| rng2.random());
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    fn sample_from_uses_given_distribution() {
+        use rand::distr::Uniform;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let dist = Uniform::new_inclusive(10, 20).unwrap();
+        let m = Matrix::<i32, 2, 2>::sample_from(&dist, &mut rng);
+        for v in m.iter() {
+            assert!((10..=20).contains(v));
+        }
+    }
+
+    #[test]
+    fn standard_uniform_distribution_for_matrix() {
+        let mut rng = StdRng::seed_from_u64(99);
+        let _m: Matrix<u8, 2, 2> = rng.random();
+    }
+
+    #[test]
+    fn floyd_warshall_shortest_paths() {
+        const INF: u32 = u32::MAX;
+        // 0 -> 1 (5), 1 -> 2 (3), 0 -> 2 (10)
+        let adj = Matrix::<u32, 3, 3>::from_rows([
+            [0, 5, 10],
+            [INF, 0, 3],
+            [INF, INF, 0],
+        ]);
+        let dist = adj.floyd_warshall();
+        assert_eq!(*dist.get(0, 2), 8);
+        assert_eq!(*dist.get(0, 1), 5);
+        assert_eq!(*dist.get(1, 2), 3);
+        assert_eq!(*dist.get(2, 0), INF);
+    }
+
+    #[test]
+    fn floyd_warshall_unreachable_stays_sentinel() {
+        const INF: u32 = u32::MAX;
+        let adj = Matrix::<u32, 2, 2>::from_rows([[0, INF], [INF, 0]]);
+        let dist = adj.floyd_warshall();
+        assert_eq!(*dist.get(0, 1), INF);
+        assert_eq!(*dist.get(1, 0), INF);
+    }
+
+    #[test]
+    fn min_bottleneck_keeps_smaller_direct_edge() {
+        const INF: u32 = u32::MAX;
+        // Direct 0->2 edge of weight 1, and a 0->1->2 path with edges 4 and 5.
+        let adj = Matrix::<u32, 3, 3>::from_rows([
+            [0, 4, 1],
+            [INF, 0, 5],
+            [INF, INF, 0],
+        ]);
+        let result = adj.min_bottleneck();
+        // Best path 0->1->2 has bottleneck max(4, 5) = 5, worse than direct 1.
+        assert_eq!(*result.get(0, 2), 1);
+        assert_eq!(*result.get(0, 1), 4);
+    }
+
+    #[test]
+    fn min_bottleneck_improves_on_direct_edge() {
+        const INF: u32 = u32::MAX;
+        // Direct 0->2 edge of capacity 10, but 0->1->2 bottlenecks at 3.
+        let adj = Matrix::<u32, 3, 3>::from_rows([
+            [0, 2, 10],
+            [INF, 0, 3],
+            [INF, INF, 0],
+        ]);
+        let result = adj.min_bottleneck();
+        assert_eq!(*result.get(0, 2), 3);
+    }
+
+    #[test]
+    fn floyd_warshall_does_not_overflow_on_large_finite_weights() {
+        const INF: u32 = u32::MAX;
+        // Both real edge weights are close to u32::MAX; summing them must not
+        // wrap or panic, and the unreachable entry must stay the sentinel.
+        let big = u32::MAX - 1;
+        let adj = Matrix::<u32, 3, 3>::from_rows([
+            [0, big, INF],
+            [INF, 0, big],
+            [INF, INF, 0],
+        ]);
+        let dist = adj.floyd_warshall();
+        assert_eq!(*dist.get(0, 2), INF);
+    }
 }